@@ -60,6 +60,14 @@ mod tests {
             Err(ProgramError::InvalidInstructionData),
             process_instruction(&program_id, &[], &bad_utf8)
         );
+
+        let truncated_utf8 = &bytes[..3]; // Missing final continuation byte
+        assert_eq!(
+            Err(ProgramError::InvalidInstructionData),
+            process_instruction(&program_id, &[], truncated_utf8)
+        );
+
+        assert_eq!(Ok(()), process_instruction(&program_id, &[], b""));
     }
 
     #[test]